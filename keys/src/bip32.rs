@@ -0,0 +1,379 @@
+//! BIP32-style hierarchical deterministic derivation for EOS secp256k1 (K1) keys.
+//!
+//! An [`ExtendedSecretKey`] wraps a plain [`SecretKey`] with the chain code,
+//! depth and parent fingerprint needed to derive further children, the way
+//! `bitcoin::util::bip32::ExtendedPrivKey` derives `bitcoin::PrivateKey`. Paths
+//! are written the usual way, e.g. `m/44'/194'/0'/0/0` (194 is EOS's SLIP-44
+//! coin type).
+
+use alloc::vec::Vec;
+use bitcoin_hashes::{hmac, ripemd160, sha256, sha512, Hash as HashTrait, HashEngine};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::network::Network;
+use crate::public::PublicKey;
+use crate::secret::{SecretKey, SecretKeyInner};
+
+/// Child indices at or above this are hardened.
+const HARDENED_BIT: u32 = 1 << 31;
+
+/// A single step in a [`DerivationPath`], e.g. the `44'` in `m/44'/194'/0'/0/0`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChildNumber {
+    /// A normal child, derivable from the extended public key alone.
+    Normal(u32),
+    /// A hardened child, derivable only from the extended private key.
+    Hardened(u32),
+}
+
+impl ChildNumber {
+    /// Whether this is a hardened index (`i >= 2^31`).
+    pub fn is_hardened(self) -> bool {
+        matches!(self, ChildNumber::Hardened(_))
+    }
+
+    /// The raw `ser32(i)` value used in the HMAC input.
+    pub fn to_bits(self) -> u32 {
+        match self {
+            ChildNumber::Normal(i) => i,
+            ChildNumber::Hardened(i) => i | HARDENED_BIT,
+        }
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let (index, hardened) = match s.strip_suffix('\'').or_else(|| s.strip_suffix('h')) {
+            Some(rest) => (rest, true),
+            None => (s, false),
+        };
+        let index: u32 = index.parse().map_err(|_| Error::InvalidChildNumber)?;
+        if index >= HARDENED_BIT {
+            return Err(Error::InvalidChildNumber);
+        }
+        Ok(if hardened {
+            ChildNumber::Hardened(index)
+        } else {
+            ChildNumber::Normal(index)
+        })
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChildNumber::Normal(i) => write!(f, "{}", i),
+            ChildNumber::Hardened(i) => write!(f, "{}'", i),
+        }
+    }
+}
+
+/// A parsed BIP32 derivation path such as `m/44'/194'/0'/0/0`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DerivationPath(Vec<ChildNumber>);
+
+impl DerivationPath {
+    /// The individual steps of the path, in order from the master key.
+    pub fn as_slice(&self) -> &[ChildNumber] {
+        &self.0
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let mut parts = s.split('/');
+        if parts.next() != Some("m") {
+            return Err(Error::InvalidPath);
+        }
+        let steps = parts
+            .map(ChildNumber::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DerivationPath(steps))
+    }
+}
+
+/// An extended secp256k1 private key: a [`SecretKey`] plus the chain code and
+/// bookkeeping needed to derive child keys, mirroring BIP32's `xprv`.
+#[derive(Clone)]
+pub struct ExtendedSecretKey {
+    /// The private key at this node of the derivation tree.
+    pub secret_key: SecretKey,
+    /// 32 bytes of additional entropy mixed into every child derivation.
+    pub chain_code: [u8; 32],
+    /// How many derivation steps below the master key this node sits.
+    pub depth: u8,
+    /// First 4 bytes of the parent's public key fingerprint, or all zero at the master.
+    pub parent_fingerprint: [u8; 4],
+    /// The `ser32(i)` value (including the hardened bit) used to reach this node.
+    pub child_number: u32,
+}
+
+impl ExtendedSecretKey {
+    /// Derive the master extended key from a BIP39-style seed.
+    pub fn new_master(network: Network, seed: &[u8]) -> Result<Self, Error> {
+        let (il, ir) = hmac_sha512(b"Bitcoin seed", seed);
+        let key = secp256k1::SecretKey::parse_slice(&il).map_err(Error::Secp256k1)?;
+
+        Ok(ExtendedSecretKey {
+            secret_key: SecretKey {
+                compressed: true,
+                network,
+                inner: SecretKeyInner::K1(key),
+            },
+            chain_code: ir,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+        })
+    }
+
+    /// The raw secp256k1 scalar backing this node. HD derivation is K1-only, so
+    /// this never sees an R1 key in practice.
+    fn k1_key(&self) -> Result<&secp256k1::SecretKey, Error> {
+        match &self.secret_key.inner {
+            SecretKeyInner::K1(key) => Ok(key),
+            SecretKeyInner::R1(_) => Err(Error::NotK1),
+        }
+    }
+
+    /// First 4 bytes of RIPEMD160(SHA256(compressed pubkey)), used to tag this
+    /// node as the parent of any children derived from it.
+    pub fn fingerprint(&self) -> Result<[u8; 4], Error> {
+        Ok(fingerprint_of(&secp256k1::PublicKey::from_secret_key(self.k1_key()?)))
+    }
+
+    /// Derive the direct child at `child`, hardened or not.
+    ///
+    /// Per BIP32, if `parse256(I_L) >= n` or the resulting key would be 0,
+    /// this index is invalid; we move on to the next index of the same kind
+    /// (normal/hardened) rather than failing the whole derivation.
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self, Error> {
+        let parent_key = self.k1_key()?.clone();
+        let hardened = child.is_hardened();
+        let mut index = match child {
+            ChildNumber::Normal(i) | ChildNumber::Hardened(i) => i,
+        };
+
+        loop {
+            let candidate = if hardened {
+                ChildNumber::Hardened(index)
+            } else {
+                ChildNumber::Normal(index)
+            };
+
+            let mut engine = hmac::HmacEngine::<sha512::Hash>::new(&self.chain_code);
+            match candidate {
+                ChildNumber::Hardened(_) => {
+                    engine.input(&[0u8]);
+                    engine.input(&parent_key.serialize());
+                }
+                ChildNumber::Normal(_) => {
+                    let pk = secp256k1::PublicKey::from_secret_key(&parent_key);
+                    engine.input(&pk.serialize_compressed());
+                }
+            }
+            engine.input(&candidate.to_bits().to_be_bytes());
+            let (il, ir) = finish_hmac_sha512(engine);
+
+            let key = match secp256k1::SecretKey::parse_slice(&il).and_then(|mut key| {
+                key.tweak_add_assign(&parent_key)?;
+                Ok(key)
+            }) {
+                Ok(key) => key,
+                Err(e) => {
+                    index = match index.checked_add(1) {
+                        Some(next) => next,
+                        None => return Err(Error::Secp256k1(e)),
+                    };
+                    continue;
+                }
+            };
+
+            return Ok(ExtendedSecretKey {
+                secret_key: SecretKey {
+                    compressed: true,
+                    network: self.secret_key.network,
+                    inner: SecretKeyInner::K1(key),
+                },
+                chain_code: ir,
+                depth: self.depth + 1,
+                parent_fingerprint: self.fingerprint()?,
+                child_number: candidate.to_bits(),
+            });
+        }
+    }
+
+    /// Walk `path` from this key (normally the master) down to the leaf private key.
+    pub fn derive_priv(&self, path: &DerivationPath) -> Result<SecretKey, Error> {
+        let mut node = self.clone();
+        for &step in path.as_slice() {
+            node = node.derive_child(step)?;
+        }
+        Ok(node.secret_key)
+    }
+
+    /// The [`PublicKey`] paired with this node's private key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from(&self.secret_key)
+    }
+}
+
+/// The public counterpart of an [`ExtendedSecretKey`]; can derive further
+/// non-hardened children without access to any private key.
+#[derive(Clone)]
+pub struct ExtendedPublicKey {
+    /// The raw public key at this node of the derivation tree.
+    pub key: secp256k1::PublicKey,
+    /// 32 bytes of additional entropy mixed into every child derivation.
+    pub chain_code: [u8; 32],
+    /// How many derivation steps below the master key this node sits.
+    pub depth: u8,
+    /// First 4 bytes of the parent's public key fingerprint, or all zero at the master.
+    pub parent_fingerprint: [u8; 4],
+    /// The `ser32(i)` value used to reach this node.
+    pub child_number: u32,
+}
+
+impl core::convert::TryFrom<&ExtendedSecretKey> for ExtendedPublicKey {
+    type Error = Error;
+
+    fn try_from(xprv: &ExtendedSecretKey) -> Result<Self, Error> {
+        Ok(ExtendedPublicKey {
+            key: secp256k1::PublicKey::from_secret_key(xprv.k1_key()?),
+            chain_code: xprv.chain_code,
+            depth: xprv.depth,
+            parent_fingerprint: xprv.parent_fingerprint,
+            child_number: xprv.child_number,
+        })
+    }
+}
+
+impl ExtendedPublicKey {
+    /// First 4 bytes of RIPEMD160(SHA256(compressed pubkey)).
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint_of(&self.key)
+    }
+
+    /// Derive a non-hardened child. Hardened children require the private key.
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self, Error> {
+        if child.is_hardened() {
+            return Err(Error::CannotDeriveHardenedPublic);
+        }
+
+        let mut engine = hmac::HmacEngine::<sha512::Hash>::new(&self.chain_code);
+        engine.input(&self.key.serialize_compressed());
+        engine.input(&child.to_bits().to_be_bytes());
+        let (il, ir) = finish_hmac_sha512(engine);
+
+        let tweak = secp256k1::SecretKey::parse_slice(&il).map_err(Error::Secp256k1)?;
+        let mut key = self.key;
+        key.tweak_add_assign(&tweak).map_err(Error::Secp256k1)?;
+
+        Ok(ExtendedPublicKey {
+            key,
+            chain_code: ir,
+            depth: self.depth + 1,
+            parent_fingerprint: self.fingerprint(),
+            child_number: child.to_bits(),
+        })
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut engine = hmac::HmacEngine::<sha512::Hash>::new(key);
+    engine.input(data);
+    finish_hmac_sha512(engine)
+}
+
+fn finish_hmac_sha512(engine: hmac::HmacEngine<sha512::Hash>) -> ([u8; 32], [u8; 32]) {
+    let i = hmac::Hmac::<sha512::Hash>::from_engine(engine).into_inner();
+    let mut il = [0u8; 32];
+    let mut ir = [0u8; 32];
+    il.copy_from_slice(&i[..32]);
+    ir.copy_from_slice(&i[32..]);
+    (il, ir)
+}
+
+fn fingerprint_of(pk: &secp256k1::PublicKey) -> [u8; 4] {
+    let sha = sha256::Hash::hash(&pk.serialize_compressed());
+    let ripemd = ripemd160::Hash::hash(&sha.into_inner());
+    let mut fp = [0u8; 4];
+    fp.copy_from_slice(&ripemd.into_inner()[..4]);
+    fp
+}
+
+/// Errors produced while deriving or parsing HD keys.
+#[derive(Debug)]
+pub enum Error {
+    /// The underlying curve operation failed (e.g. an out-of-range scalar).
+    Secp256k1(secp256k1::Error),
+    /// A path component wasn't a valid unsigned 31-bit index, with an optional `'`/`h` suffix.
+    InvalidChildNumber,
+    /// A path didn't start with `m`.
+    InvalidPath,
+    /// Attempted to derive a hardened child from an extended *public* key.
+    CannotDeriveHardenedPublic,
+    /// HD derivation only supports the K1 curve; this node holds an R1 key.
+    NotK1,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Secp256k1(e) => write!(f, "secp256k1 error: {}", e),
+            Error::InvalidChildNumber => write!(f, "invalid child number"),
+            Error::InvalidPath => write!(f, "derivation path must start with 'm'"),
+            Error::CannotDeriveHardenedPublic => {
+                write!(f, "cannot derive a hardened child from an extended public key")
+            }
+            Error::NotK1 => write!(f, "HD derivation requires a K1 key"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn child_number_parses_hardened_and_normal() {
+        assert_eq!(ChildNumber::from_str("44'").unwrap(), ChildNumber::Hardened(44));
+        assert_eq!(ChildNumber::from_str("0").unwrap(), ChildNumber::Normal(0));
+    }
+
+    #[test]
+    fn derivation_path_parses_eos_coin_type() {
+        let path = DerivationPath::from_str("m/44'/194'/0'/0/0").unwrap();
+        assert_eq!(
+            path.as_slice(),
+            &[
+                ChildNumber::Hardened(44),
+                ChildNumber::Hardened(194),
+                ChildNumber::Hardened(0),
+                ChildNumber::Normal(0),
+                ChildNumber::Normal(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn master_key_then_derive_priv_agree() {
+        let seed = [0x5au8; 64];
+        let master = ExtendedSecretKey::new_master(Network::Mainnet, &seed).unwrap();
+        let path = DerivationPath::from_str("m/44'/194'/0'/0/0").unwrap();
+
+        let via_path = master.derive_priv(&path).unwrap();
+
+        let mut node = master.clone();
+        for step in path.as_slice() {
+            node = node.derive_child(*step).unwrap();
+        }
+
+        assert_eq!(via_path.to_bytes(), node.secret_key.to_bytes());
+    }
+}
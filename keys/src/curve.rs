@@ -0,0 +1,72 @@
+//! The elliptic curve an EOS key lives on.
+//!
+//! EOS keys are tagged with a curve suffix in every modern string encoding
+//! (`PVT_K1_...`, `PUB_R1_...`, `SIG_K1_...`) so that signers and verifiers
+//! agree on which curve's arithmetic to use.
+
+use core::fmt;
+use core::str::FromStr;
+
+/// Which elliptic curve a key or signature was generated on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CurveType {
+    /// secp256k1, the curve EOS has used since launch.
+    K1,
+    /// NIST P-256, used by hardware-backed and WebAuthn credentials.
+    R1,
+}
+
+impl CurveType {
+    /// The suffix used in string encodings, e.g. `PVT_K1_...` / `PUB_R1_...`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CurveType::K1 => "K1",
+            CurveType::R1 => "R1",
+        }
+    }
+}
+
+impl fmt::Display for CurveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CurveType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "K1" => Ok(CurveType::K1),
+            "R1" => Ok(CurveType::R1),
+            _ => Err(Error::UnknownCurve),
+        }
+    }
+}
+
+/// Errors produced while parsing a [`CurveType`].
+#[derive(Debug)]
+pub enum Error {
+    /// The suffix wasn't `K1` or `R1`.
+    UnknownCurve,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown curve type")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn round_trips_through_display_and_from_str() {
+        assert_eq!(CurveType::from_str("K1").unwrap(), CurveType::K1);
+        assert_eq!(CurveType::from_str("R1").unwrap(), CurveType::R1);
+        assert_eq!(CurveType::K1.to_string(), "K1");
+        assert_eq!(CurveType::R1.to_string(), "R1");
+    }
+}
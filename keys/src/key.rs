@@ -0,0 +1,64 @@
+//! A focused error type for [`crate::secret::SecretKey`], replacing the
+//! catch-all `error::Error` this crate used before splitting out curve and
+//! keystore support. Callers can now match on e.g. a bad checksum vs. an
+//! out-of-range scalar instead of getting one undifferentiated base58 error.
+
+use core::fmt;
+
+use crate::base58;
+use crate::curve::CurveType;
+use secp256k1;
+
+/// Errors produced while constructing, parsing or using a [`crate::secret::SecretKey`].
+#[derive(Debug)]
+pub enum Error {
+    /// The base58/checksum envelope around the key was malformed.
+    Base58(base58::Error),
+    /// The decoded bytes weren't a valid scalar for the curve.
+    Secp256k1(secp256k1::Error),
+    /// A WIF string decoded to neither 33 (uncompressed) nor 34 (compressed) bytes.
+    InvalidWifLength(usize),
+    /// The WIF version byte didn't match a known network.
+    UnknownNetworkByte(u8),
+    /// The operation isn't supported for this key's curve.
+    UnsupportedCurve(CurveType),
+    /// The string wasn't a recognized `PVT_...` or WIF encoding.
+    UnknownKeyFormat,
+    /// An R1 signature or recovery id was malformed, or recovery/verification failed.
+    InvalidSignature,
+    /// Sealing a key under a passphrase failed, or the scrypt parameters were
+    /// invalid (when encrypting or when decrypting).
+    Encryption,
+    /// The ciphertext failed to authenticate, almost always a wrong passphrase.
+    DecryptionFailed,
+}
+
+impl From<base58::Error> for Error {
+    fn from(e: base58::Error) -> Self {
+        Error::Base58(e)
+    }
+}
+
+impl From<secp256k1::Error> for Error {
+    fn from(e: secp256k1::Error) -> Self {
+        Error::Secp256k1(e)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Base58(e) => write!(f, "base58 error: {}", e),
+            Error::Secp256k1(e) => write!(f, "secp256k1 error: {}", e),
+            Error::InvalidWifLength(len) => write!(f, "invalid WIF length: {}", len),
+            Error::UnknownNetworkByte(b) => write!(f, "unknown network byte: {}", b),
+            Error::UnsupportedCurve(curve) => write!(f, "unsupported curve: {}", curve),
+            Error::UnknownKeyFormat => write!(f, "unrecognized key string format"),
+            Error::InvalidSignature => write!(f, "invalid or unverifiable R1 signature"),
+            Error::Encryption => write!(f, "failed to derive passphrase key or encrypt key"),
+            Error::DecryptionFailed => write!(f, "failed to decrypt key (wrong passphrase?)"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
@@ -0,0 +1,187 @@
+//! Passphrase-encrypted export/import of a [`SecretKey`], so it can be backed
+//! up without ever touching disk as plaintext WIF. Derives a symmetric key
+//! from the passphrase with scrypt, then seals the key bytes with
+//! XChaCha20-Poly1305 under a random nonce. Requires the `encrypt` feature,
+//! which is left off the default feature set so `no_std` builds don't pull in
+//! the extra dependencies.
+
+use alloc::vec::Vec;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
+use scrypt::{scrypt, Params as ScryptParams};
+
+use crate::curve::CurveType;
+use crate::key;
+use crate::network::Network;
+use crate::secret::{SecretKey, SecretKeyInner};
+use secp256k1;
+
+/// scrypt cost parameters used to derive the symmetric key from a passphrase.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KdfParams {
+    /// log2(N), the CPU/memory cost parameter.
+    pub log_n: u8,
+    /// Block size parameter.
+    pub r: u32,
+    /// Parallelization parameter.
+    pub p: u32,
+}
+
+impl Default for KdfParams {
+    /// A cost lighter than `scrypt`'s own `Params::recommended()` (`log_n: 17`),
+    /// chosen to keep interactive key export fast; callers needing the
+    /// stronger default can still set `log_n: 17` explicitly.
+    fn default() -> Self {
+        KdfParams { log_n: 15, r: 8, p: 1 }
+    }
+}
+
+/// A [`SecretKey`] sealed under a passphrase. Serializable to JSON/bytes via
+/// `serde` (behind the `serde` feature) so it can be written to disk or a keystore file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncryptedKey {
+    /// Random scrypt salt.
+    pub salt: [u8; 16],
+    /// Random XChaCha20-Poly1305 nonce.
+    pub nonce: [u8; 24],
+    /// The KDF cost parameters used, so a different default later can still decrypt old keys.
+    pub kdf_params: KdfParams,
+    /// The sealed `curve_tag || compressed_flag || network_tag || key_bytes`
+    /// plaintext, plus the AEAD tag.
+    pub ciphertext: Vec<u8>,
+}
+
+impl SecretKey {
+    /// Seal this key under `passphrase`, generating a fresh salt and nonce from `rng`.
+    pub fn encrypt<R: RngCore>(&self, passphrase: &str, rng: &mut R) -> Result<EncryptedKey, key::Error> {
+        let mut salt = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; 24];
+        rng.fill_bytes(&mut nonce);
+
+        let kdf_params = KdfParams::default();
+        let symmetric_key = derive_key(passphrase, &salt, kdf_params)?;
+
+        let mut plaintext = Vec::with_capacity(35);
+        plaintext.push(curve_tag(self.curve()));
+        plaintext.push(self.compressed as u8);
+        plaintext.push(network_tag(self.network));
+        plaintext.extend_from_slice(&self.to_bytes());
+
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&symmetric_key));
+        let ciphertext = cipher
+            .encrypt(XNonce::from_slice(&nonce), plaintext.as_ref())
+            .map_err(|_| key::Error::Encryption)?;
+
+        Ok(EncryptedKey { salt, nonce, kdf_params, ciphertext })
+    }
+}
+
+impl EncryptedKey {
+    /// Recover the [`SecretKey`], failing with [`key::Error::DecryptionFailed`]
+    /// if `passphrase` is wrong (an authentication failure, not a parse error).
+    pub fn decrypt(&self, passphrase: &str) -> Result<SecretKey, key::Error> {
+        let symmetric_key = derive_key(passphrase, &self.salt, self.kdf_params)?;
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&symmetric_key));
+        let plaintext = cipher
+            .decrypt(XNonce::from_slice(&self.nonce), self.ciphertext.as_ref())
+            .map_err(|_| key::Error::DecryptionFailed)?;
+
+        if plaintext.len() != 35 {
+            return Err(key::Error::DecryptionFailed);
+        }
+
+        let compressed = match plaintext[1] {
+            0 => false,
+            1 => true,
+            _ => return Err(key::Error::DecryptionFailed),
+        };
+        let network = network_from_tag(plaintext[2]).ok_or(key::Error::DecryptionFailed)?;
+
+        let inner = match plaintext[0] {
+            0 => SecretKeyInner::K1(
+                secp256k1::SecretKey::parse_slice(&plaintext[3..]).map_err(|_| key::Error::DecryptionFailed)?,
+            ),
+            1 => SecretKeyInner::R1(
+                p256::SecretKey::from_bytes((&plaintext[3..]).into()).map_err(|_| key::Error::DecryptionFailed)?,
+            ),
+            _ => return Err(key::Error::DecryptionFailed),
+        };
+
+        Ok(SecretKey { compressed, network, inner })
+    }
+}
+
+fn curve_tag(curve: CurveType) -> u8 {
+    match curve {
+        CurveType::K1 => 0,
+        CurveType::R1 => 1,
+    }
+}
+
+fn network_tag(network: Network) -> u8 {
+    match network {
+        Network::Mainnet => 0,
+        Network::Testnet => 1,
+    }
+}
+
+fn network_from_tag(tag: u8) -> Option<Network> {
+    match tag {
+        0 => Some(Network::Mainnet),
+        1 => Some(Network::Testnet),
+        _ => None,
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8; 16], params: KdfParams) -> Result<[u8; 32], key::Error> {
+    let scrypt_params =
+        ScryptParams::new(params.log_n, params.r, params.p, 32).map_err(|_| key::Error::Encryption)?;
+    let mut symmetric_key = [0u8; 32];
+    scrypt(passphrase.as_bytes(), salt, &scrypt_params, &mut symmetric_key).map_err(|_| key::Error::Encryption)?;
+    Ok(symmetric_key)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    fn encrypt_then_decrypt_round_trips() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate(&mut rng);
+
+        let encrypted = sk.encrypt("hunter2", &mut rng).unwrap();
+        let decrypted = encrypted.decrypt("hunter2").unwrap();
+
+        assert_eq!(decrypted.to_bytes(), sk.to_bytes());
+    }
+
+    #[test]
+    fn encrypt_then_decrypt_preserves_compressed_and_network() {
+        let mut rng = thread_rng();
+        let mut sk = SecretKey::generate(&mut rng);
+        sk.compressed = true;
+        sk.network = Network::Testnet;
+
+        let encrypted = sk.encrypt("hunter2", &mut rng).unwrap();
+        let decrypted = encrypted.decrypt("hunter2").unwrap();
+
+        assert_eq!(decrypted.to_bytes(), sk.to_bytes());
+        assert_eq!(decrypted.compressed, sk.compressed);
+        assert_eq!(decrypted.network, sk.network);
+    }
+
+    #[test]
+    fn wrong_passphrase_fails_authentication() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate(&mut rng);
+
+        let encrypted = sk.encrypt("hunter2", &mut rng).unwrap();
+        assert!(matches!(encrypted.decrypt("wrong"), Err(key::Error::DecryptionFailed)));
+    }
+}
@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod base58;
+pub mod bip32;
+pub mod curve;
+pub mod error;
+pub mod key;
+#[cfg(feature = "encrypt")]
+pub mod keystore;
+pub mod mnemonic;
+pub mod network;
+pub mod public;
+pub mod secret;
+pub mod signature;
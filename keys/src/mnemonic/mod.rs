@@ -0,0 +1,189 @@
+//! BIP39 mnemonic phrases: a human-readable backup for the seeds consumed by
+//! [`crate::bip32`]. English words only; since the wordlist is pure ASCII,
+//! NFKD normalization of the phrase is a no-op and is skipped.
+
+mod wordlist;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::vec;
+use bitcoin_hashes::{hmac, sha256, sha512, Hash as HashTrait, HashEngine};
+use core::fmt;
+use rand::Rng;
+
+use self::wordlist::WORDLIST;
+
+const PBKDF2_ROUNDS: u32 = 2048;
+const VALID_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// A BIP39 mnemonic phrase, checksum-validated on construction.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Mnemonic {
+    phrase: String,
+}
+
+impl Mnemonic {
+    /// Generate a new mnemonic with `word_count` words (12, 15, 18, 21 or 24),
+    /// drawing `word_count / 3 * 32` bits of entropy from `rng`.
+    pub fn generate<R: Rng>(rng: &mut R, word_count: usize) -> Result<Self, Error> {
+        if !VALID_WORD_COUNTS.contains(&word_count) {
+            return Err(Error::InvalidWordCount(word_count));
+        }
+        let entropy_bytes = word_count * 11 * 32 / 33 / 8;
+        let mut entropy = vec![0u8; entropy_bytes];
+        rng.fill_bytes(&mut entropy);
+        Ok(Self::from_entropy(&entropy))
+    }
+
+    fn from_entropy(entropy: &[u8]) -> Self {
+        let checksum_bits = entropy.len() * 8 / 32;
+        let checksum_byte = sha256::Hash::hash(entropy).into_inner()[0];
+
+        let mut bits: Vec<bool> = Vec::with_capacity(entropy.len() * 8 + checksum_bits);
+        for byte in entropy {
+            for i in (0..8).rev() {
+                bits.push((byte >> i) & 1 == 1);
+            }
+        }
+        for i in 0..checksum_bits {
+            bits.push((checksum_byte >> (7 - i)) & 1 == 1);
+        }
+
+        let phrase = bits
+            .chunks(11)
+            .map(|group| WORDLIST[group.iter().fold(0usize, |acc, &b| (acc << 1) | b as usize)])
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        Mnemonic { phrase }
+    }
+
+    /// Parse a space-separated phrase, rejecting unknown words or a bad checksum.
+    pub fn from_phrase(phrase: &str) -> Result<Self, Error> {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if !VALID_WORD_COUNTS.contains(&words.len()) {
+            return Err(Error::InvalidWordCount(words.len()));
+        }
+
+        let mut bits: Vec<bool> = Vec::with_capacity(words.len() * 11);
+        for word in &words {
+            let index = WORDLIST
+                .iter()
+                .position(|w| w == word)
+                .ok_or_else(|| Error::UnknownWord((*word).to_string()))?;
+            for i in (0..11).rev() {
+                bits.push((index >> i) & 1 == 1);
+            }
+        }
+
+        let checksum_bits = words.len() * 11 / 33;
+        let entropy_bits = words.len() * 11 - checksum_bits;
+        let entropy: Vec<u8> = bits[..entropy_bits]
+            .chunks(8)
+            .map(|byte_bits| byte_bits.iter().fold(0u8, |acc, &b| (acc << 1) | b as u8))
+            .collect();
+
+        let checksum_byte = sha256::Hash::hash(&entropy).into_inner()[0];
+        for i in 0..checksum_bits {
+            let expected = (checksum_byte >> (7 - i)) & 1 == 1;
+            if bits[entropy_bits + i] != expected {
+                return Err(Error::InvalidChecksum);
+            }
+        }
+
+        Ok(Mnemonic { phrase: phrase.to_string() })
+    }
+
+    /// The space-separated phrase.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Derive the 64-byte seed that [`crate::bip32::ExtendedSecretKey::new_master`]
+    /// expects, salted with an optional passphrase.
+    pub fn to_seed(&self, passphrase: &str) -> [u8; 64] {
+        let mut salt = String::from("mnemonic");
+        salt.push_str(passphrase);
+        pbkdf2_hmac_sha512(self.phrase.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS)
+    }
+}
+
+/// PBKDF2-HMAC-SHA512 with a 64-byte output, i.e. exactly one HMAC-SHA512 output,
+/// so only a single `F(password, salt, rounds, blockIndex=1)` block is needed.
+fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], rounds: u32) -> [u8; 64] {
+    let mut block_salt = Vec::with_capacity(salt.len() + 4);
+    block_salt.extend_from_slice(salt);
+    block_salt.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut u = hmac_sha512(password, &block_salt);
+    let mut result = u;
+    for _ in 1..rounds {
+        u = hmac_sha512(password, &u);
+        for (r, u_byte) in result.iter_mut().zip(u.iter()) {
+            *r ^= u_byte;
+        }
+    }
+    result
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut engine = hmac::HmacEngine::<sha512::Hash>::new(key);
+    engine.input(data);
+    hmac::Hmac::<sha512::Hash>::from_engine(engine).into_inner()
+}
+
+/// Errors produced while generating or parsing a mnemonic.
+#[derive(Debug)]
+pub enum Error {
+    /// Word count wasn't one of 12, 15, 18, 21 or 24.
+    InvalidWordCount(usize),
+    /// A word in the phrase isn't in the English wordlist.
+    UnknownWord(String),
+    /// The trailing checksum bits didn't match the entropy.
+    InvalidChecksum,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidWordCount(n) => write!(f, "invalid word count: {}", n),
+            Error::UnknownWord(w) => write!(f, "word not in wordlist: {}", w),
+            Error::InvalidChecksum => write!(f, "mnemonic checksum mismatch"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    fn generate_then_parse_round_trips() {
+        let mut rng = thread_rng();
+        let mnemonic = Mnemonic::generate(&mut rng, 12).unwrap();
+        assert_eq!(mnemonic.phrase().split_whitespace().count(), 12);
+        let parsed = Mnemonic::from_phrase(mnemonic.phrase()).unwrap();
+        assert_eq!(parsed, mnemonic);
+    }
+
+    #[test]
+    fn from_phrase_rejects_unknown_word() {
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                       abandon abandon abandon abandon abandon notaword";
+        assert!(matches!(Mnemonic::from_phrase(phrase), Err(Error::UnknownWord(_))));
+    }
+
+    #[test]
+    fn from_phrase_rejects_bad_checksum() {
+        let phrase = "abandon abandon abandon abandon abandon abandon \
+                       abandon abandon abandon abandon abandon abandon";
+        assert!(matches!(Mnemonic::from_phrase(phrase), Err(Error::InvalidChecksum)));
+    }
+
+    #[test]
+    fn to_seed_is_deterministic() {
+        let mnemonic = Mnemonic::from_entropy(&[0u8; 16]);
+        assert_eq!(mnemonic.to_seed(""), mnemonic.to_seed(""));
+    }
+}
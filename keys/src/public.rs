@@ -0,0 +1,280 @@
+//! The public counterpart of a [`crate::secret::SecretKey`], on either curve.
+//! K1 keys display in the legacy `EOS...` base58 format, with round-trip
+//! support for the modern `PUB_K1_...` encoding via
+//! [`PublicKey::to_pub_string`]/[`PublicKey::from_pub_string`]; R1 keys only
+//! have the modern `PUB_R1_...` encoding.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use bitcoin_hashes::{ripemd160, sha256, Hash as HashTrait};
+use core::fmt;
+use core::str::FromStr;
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+use crate::base58;
+use crate::curve::CurveType;
+use crate::key;
+use crate::network::Network;
+use crate::secret::{curve_checksum, SecretKey, SecretKeyInner};
+use crate::signature::{Signature, SignatureInner};
+use secp256k1;
+
+/// The curve-specific key material backing a [`PublicKey`].
+#[derive(Clone, PartialEq, Eq)]
+pub enum PublicKeyInner {
+    /// A secp256k1 point.
+    K1(secp256k1::PublicKey),
+    /// A NIST P-256 point.
+    R1(p256::ecdsa::VerifyingKey),
+}
+
+/// A public key, on either the K1 (secp256k1) or R1 (NIST P-256) curve.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PublicKey {
+    /// The compressed flag carried over from the secret key. [`PublicKey::to_bytes`]
+    /// and every string encoding always use the compressed form regardless.
+    pub compressed: bool,
+    /// The network on which this key should be used.
+    pub network: Network,
+    /// The curve-specific key material.
+    pub inner: PublicKeyInner,
+}
+
+impl From<&SecretKey> for PublicKey {
+    /// Derive the public key paired with `sk`.
+    fn from(sk: &SecretKey) -> Self {
+        let inner = match &sk.inner {
+            SecretKeyInner::K1(k) => PublicKeyInner::K1(secp256k1::PublicKey::from_secret_key(k)),
+            SecretKeyInner::R1(k) => {
+                let signing_key = p256::ecdsa::SigningKey::from(k.clone());
+                PublicKeyInner::R1(*signing_key.verifying_key())
+            }
+        };
+
+        PublicKey {
+            compressed: sk.compressed,
+            network: sk.network,
+            inner,
+        }
+    }
+}
+
+impl PublicKey {
+    /// Which curve this key is on.
+    pub fn curve(&self) -> CurveType {
+        match self.inner {
+            PublicKeyInner::K1(_) => CurveType::K1,
+            PublicKeyInner::R1(_) => CurveType::R1,
+        }
+    }
+
+    /// Serialize the public key to (compressed) bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match &self.inner {
+            PublicKeyInner::K1(key) => key.serialize_compressed().to_vec(),
+            PublicKeyInner::R1(key) => key.to_encoded_point(true).as_bytes().to_vec(),
+        }
+    }
+
+    /// Verify that `signature` was produced by this key's owner over `message_slice`.
+    /// Always `false` if `signature` is on a different curve than this key.
+    pub fn verify(&self, message_slice: &[u8], signature: &Signature) -> bool {
+        let msg_hash = sha256::Hash::hash(message_slice);
+
+        match (&self.inner, &signature.inner) {
+            (PublicKeyInner::K1(key), SignatureInner::K1 { sig, .. }) => {
+                let msg = secp256k1::Message::parse(&msg_hash.into_inner());
+                secp256k1::verify(&msg, sig, key)
+            }
+            (PublicKeyInner::R1(key), SignatureInner::R1 { sig, .. }) => {
+                key.verify_prehash(&msg_hash.into_inner(), sig).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse the legacy `EOS<base58(key_bytes || checksum)>` string encoding. K1 only.
+    pub fn from_eos_string(s: &str) -> Result<PublicKey, key::Error> {
+        let body = s.strip_prefix("EOS").ok_or(key::Error::UnknownKeyFormat)?;
+        let data = base58::decode(body)?;
+        if data.len() != 37 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
+        }
+        let (key_bytes, checksum) = data.split_at(33);
+        if checksum != ripemd160::Hash::hash(key_bytes).into_inner()[..4] {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        Ok(PublicKey {
+            compressed: true,
+            network: Network::Mainnet,
+            inner: PublicKeyInner::K1(secp256k1::PublicKey::parse_slice(key_bytes, None)?),
+        })
+    }
+
+    /// Format this key as the modern `PUB_K1_<base58(key_bytes || checksum)>` string,
+    /// the encoding current `cleos`/`eosjs` tooling emits in place of the legacy `EOS...` format.
+    pub fn to_pub_string(&self) -> Result<String, key::Error> {
+        if self.curve() != CurveType::K1 {
+            return Err(key::Error::UnsupportedCurve(self.curve()));
+        }
+
+        let key_bytes = self.to_bytes();
+        let mut data = Vec::with_capacity(37);
+        data.extend_from_slice(&key_bytes);
+        data.extend_from_slice(&curve_checksum(&key_bytes, CurveType::K1));
+
+        let mut out = String::from("PUB_K1_");
+        out.push_str(&base58::encode_slice(&data));
+        Ok(out)
+    }
+
+    /// Parse the `PUB_K1_<base58(key_bytes || checksum)>` string encoding of a K1 public key.
+    pub fn from_pub_string(s: &str) -> Result<PublicKey, key::Error> {
+        let body = s.strip_prefix("PUB_K1_").ok_or(key::Error::UnknownKeyFormat)?;
+        let data = base58::decode(body)?;
+        if data.len() != 37 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
+        }
+        let (key_bytes, checksum) = data.split_at(33);
+        if checksum != curve_checksum(key_bytes, CurveType::K1).as_slice() {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        Ok(PublicKey {
+            compressed: true,
+            network: Network::Mainnet,
+            inner: PublicKeyInner::K1(secp256k1::PublicKey::parse_slice(key_bytes, None)?),
+        })
+    }
+
+    /// Format this key as the modern `PUB_R1_<base58(key_bytes || checksum)>` string.
+    pub fn to_pub_r1_string(&self) -> Result<String, key::Error> {
+        if self.curve() != CurveType::R1 {
+            return Err(key::Error::UnsupportedCurve(self.curve()));
+        }
+
+        let key_bytes = self.to_bytes();
+        let mut data = Vec::with_capacity(37);
+        data.extend_from_slice(&key_bytes);
+        data.extend_from_slice(&curve_checksum(&key_bytes, CurveType::R1));
+
+        let mut out = String::from("PUB_R1_");
+        out.push_str(&base58::encode_slice(&data));
+        Ok(out)
+    }
+
+    /// Parse the `PUB_R1_<base58(key_bytes || checksum)>` string encoding of an R1 public key.
+    pub fn from_pub_r1_string(s: &str) -> Result<PublicKey, key::Error> {
+        let body = s.strip_prefix("PUB_R1_").ok_or(key::Error::UnknownKeyFormat)?;
+        let data = base58::decode(body)?;
+        if data.len() != 37 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
+        }
+        let (key_bytes, checksum) = data.split_at(33);
+        if checksum != curve_checksum(key_bytes, CurveType::R1).as_slice() {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        let point = p256::EncodedPoint::from_bytes(key_bytes).map_err(|_| key::Error::UnknownKeyFormat)?;
+        let key = p256::ecdsa::VerifyingKey::from_encoded_point(&point).map_err(|_| key::Error::UnknownKeyFormat)?;
+
+        Ok(PublicKey {
+            compressed: true,
+            network: Network::Mainnet,
+            inner: PublicKeyInner::R1(key),
+        })
+    }
+}
+
+impl fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let key_bytes = self.to_bytes();
+
+        match self.curve() {
+            CurveType::K1 => {
+                let checksum = ripemd160::Hash::hash(&key_bytes).into_inner();
+                let mut data = Vec::with_capacity(37);
+                data.extend_from_slice(&key_bytes);
+                data.extend_from_slice(&checksum[..4]);
+                write!(f, "EOS{}", base58::encode_slice(&data))
+            }
+            CurveType::R1 => {
+                let mut data = Vec::with_capacity(37);
+                data.extend_from_slice(&key_bytes);
+                data.extend_from_slice(&curve_checksum(&key_bytes, CurveType::R1));
+                write!(f, "PUB_R1_{}", base58::encode_slice(&data))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = key::Error;
+    fn from_str(s: &str) -> Result<PublicKey, key::Error> {
+        if s.starts_with("PUB_K1_") {
+            PublicKey::from_pub_string(s)
+        } else if s.starts_with("PUB_R1_") {
+            PublicKey::from_pub_r1_string(s)
+        } else {
+            PublicKey::from_eos_string(s)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::secret::SecretKey;
+    use alloc::string::ToString;
+    #[cfg(feature = "std")]
+    use rand::thread_rng;
+
+    #[test]
+    fn from_str_round_trips_display() {
+        let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F").unwrap();
+        let pk = PublicKey::from(&sk);
+        let parsed: PublicKey = pk.to_string().parse().unwrap();
+        assert_eq!(parsed.to_bytes(), pk.to_bytes());
+    }
+
+    #[test]
+    fn pub_string_round_trips() {
+        let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F").unwrap();
+        let pk = PublicKey::from(&sk);
+
+        let pub_string = pk.to_pub_string().unwrap();
+        assert!(pub_string.starts_with("PUB_K1_"));
+
+        let parsed = PublicKey::from_pub_string(&pub_string).unwrap();
+        assert_eq!(parsed.to_bytes(), pk.to_bytes());
+
+        let via_from_str: PublicKey = pub_string.parse().unwrap();
+        assert_eq!(via_from_str.to_bytes(), pk.to_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn r1_public_key_from_secret_and_pub_r1_string_round_trip() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate_r1(&mut rng);
+        let pk = PublicKey::from(&sk);
+
+        let pub_string = pk.to_pub_r1_string().unwrap();
+        assert!(pub_string.starts_with("PUB_R1_"));
+        assert_eq!(pk.to_string(), pub_string);
+
+        let parsed = PublicKey::from_pub_r1_string(&pub_string).unwrap();
+        assert_eq!(parsed.to_bytes(), pk.to_bytes());
+
+        let via_from_str: PublicKey = pub_string.parse().unwrap();
+        assert_eq!(via_from_str.to_bytes(), pk.to_bytes());
+    }
+}
@@ -1,52 +1,86 @@
 use alloc::vec::Vec;
 use alloc::string::String;
-use alloc::vec;
-use bitcoin_hashes::{sha256, Hash as HashTrait};
+use bitcoin_hashes::{ripemd160, sha256, Hash as HashTrait, HashEngine};
 use core::fmt::{self, Write};
 use core::str::FromStr;
-use crate::error;
+use crate::key;
 use crate::network::Network;
 use crate::base58;
 use crate::network::Network::Mainnet;
-use crate::signature::Signature;
+use crate::curve::CurveType;
+use crate::signature::{Signature, SignatureInner};
 use rand::Rng;
 use secp256k1;
 
 
-/// A Secp256k1 private key
+/// The curve-specific key material backing a [`SecretKey`].
+#[derive(Clone, PartialEq, Eq)]
+pub enum SecretKeyInner {
+    /// A secp256k1 scalar.
+    K1(secp256k1::SecretKey),
+    /// A NIST P-256 scalar.
+    R1(p256::SecretKey),
+}
+
+/// A private key, on either the K1 (secp256k1) or R1 (NIST P-256) curve
 #[derive(Clone, PartialEq, Eq)]
 pub struct SecretKey {
     /// Whether this private key should be serialized as compressed
     pub compressed: bool,
     /// The network on which this key should be used
     pub network: Network,
-    /// The actual Secp256k1 key
-    pub key: secp256k1::SecretKey,
+    /// The curve-specific key material
+    pub inner: SecretKeyInner,
 }
 
 impl SecretKey {
-    /// Creates a new random secret key. Requires compilation with the "rand" feature.
+    /// Creates a new random K1 secret key. Requires compilation with the "rand" feature.
     pub fn generate<R>(csprng: &mut R) -> Self where R: Rng {
         Self {
             compressed: false,
             network: Mainnet,
-            key: secp256k1::SecretKey::random(csprng),
+            inner: SecretKeyInner::K1(secp256k1::SecretKey::random(csprng)),
+        }
+    }
+
+    /// Creates a new random R1 secret key. Requires compilation with the "rand" feature.
+    pub fn generate_r1<R>(csprng: &mut R) -> Self where R: Rng {
+        Self {
+            compressed: false,
+            network: Mainnet,
+            inner: SecretKeyInner::R1(p256::SecretKey::random(csprng)),
+        }
+    }
+
+    /// Which curve this key is on.
+    pub fn curve(&self) -> CurveType {
+        match self.inner {
+            SecretKeyInner::K1(_) => CurveType::K1,
+            SecretKeyInner::R1(_) => CurveType::R1,
         }
     }
 
     /// Serialize the private key to bytes
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.key.serialize().to_vec()
+        match &self.inner {
+            SecretKeyInner::K1(key) => key.serialize().to_vec(),
+            SecretKeyInner::R1(key) => key.to_bytes().to_vec(),
+        }
     }
 
-    /// Format the private key to WIF format.
+    /// Format the private key to WIF format. Only the K1 curve has a legacy WIF encoding.
     pub fn fmt_wif(&self, fmt: &mut dyn fmt::Write) -> fmt::Result {
+        let key = match &self.inner {
+            SecretKeyInner::K1(key) => key,
+            SecretKeyInner::R1(_) => return Err(fmt::Error),
+        };
+
         let mut ret = [0; 34];
         ret[0] = match self.network {
             Network::Mainnet => 128,
             Network::Testnet => 239,
         };
-        ret[1..33].copy_from_slice(&self.key.serialize());
+        ret[1..33].copy_from_slice(&key.serialize());
         let privkey = if self.compressed {
             ret[33] = 1;
             base58::check_encode_slice(&ret[..])
@@ -57,40 +91,45 @@ impl SecretKey {
         fmt.write_str(&privkey)
     }
 
-    /// Get WIF encoding of this private key.
-    pub fn to_wif(&self) -> String {
+    /// Get WIF encoding of this private key. Only the K1 curve has a legacy WIF encoding;
+    /// R1 keys return [`key::Error::UnsupportedCurve`].
+    pub fn to_wif(&self) -> Result<String, key::Error> {
+        if self.curve() != CurveType::K1 {
+            return Err(key::Error::UnsupportedCurve(self.curve()));
+        }
+
         let mut buf = String::new();
-        buf.write_fmt(format_args!("{}", self)).unwrap();
+        buf.write_fmt(format_args!("{}", self)).map_err(|_| key::Error::UnsupportedCurve(self.curve()))?;
         buf.shrink_to_fit();
 
-        buf
+        Ok(buf)
     }
 
     /// Parse WIF encoded private key.
-    pub fn from_wif(wif: &str) -> Result<SecretKey, error::Error> {
+    pub fn from_wif(wif: &str) -> Result<SecretKey, key::Error> {
         let data = base58::from_check(wif)?;
 
         let compressed = match data.len() {
             33 => false,
             34 => true,
-            _ => { return Err(error::Error::Base58(base58::Error::InvalidLength(data.len()))); }
+            _ => { return Err(key::Error::InvalidWifLength(data.len())); }
         };
 
         let network = match data[0] {
             128 => Network::Mainnet,
             239 => Network::Testnet,
-            x => { return Err(error::Error::Base58(base58::Error::InvalidVersion(vec![x]))); }
+            x => { return Err(key::Error::UnknownNetworkByte(x)); }
         };
 
         Ok(SecretKey {
             compressed,
             network,
-            key: secp256k1::SecretKey::parse_slice(&data[1..33])?,
+            inner: SecretKeyInner::K1(secp256k1::SecretKey::parse_slice(&data[1..33])?),
         })
     }
 
-    /// Deserialize a secret key from a slice
-    pub fn from_slice(data: &[u8]) -> Result<SecretKey, error::Error> {
+    /// Deserialize a K1 secret key from a slice
+    pub fn from_slice(data: &[u8]) -> Result<SecretKey, key::Error> {
         let compressed: bool = match data.len() {
             33 => true,
             65 => false,
@@ -100,20 +139,88 @@ impl SecretKey {
         Ok(SecretKey {
             compressed,
             network: Mainnet,
-            key: secp256k1::SecretKey::parse_slice(data)?,
+            inner: SecretKeyInner::K1(secp256k1::SecretKey::parse_slice(data)?),
         })
     }
 
-    pub fn sign(&self, message_slice: &[u8]) -> Signature {
-        let msg_hash = sha256::Hash::hash(&message_slice);
-        let msg = secp256k1::Message::parse(&msg_hash.into_inner());
-        let (mut sig, recv_id) = secp256k1::sign(&msg, &self.key);
-        sig.normalize_s();
+    /// Format this key as the modern `PVT_K1_<base58(key_bytes || checksum)>` string,
+    /// the encoding current `cleos`/`eosjs` tooling emits in place of legacy WIF.
+    pub fn to_pvt_string(&self) -> Result<String, key::Error> {
+        let key = match &self.inner {
+            SecretKeyInner::K1(key) => key,
+            SecretKeyInner::R1(_) => return Err(key::Error::UnsupportedCurve(self.curve())),
+        };
+
+        let key_bytes = key.serialize();
+        let mut data = Vec::with_capacity(36);
+        data.extend_from_slice(&key_bytes);
+        data.extend_from_slice(&curve_checksum(&key_bytes, CurveType::K1));
+
+        let mut out = String::from("PVT_K1_");
+        out.push_str(&base58::encode_slice(&data));
+        Ok(out)
+    }
+
+    /// Parse the `PVT_K1_<base58(key_bytes || checksum)>` string encoding of a K1 key.
+    pub fn from_pvt_string(s: &str) -> Result<SecretKey, key::Error> {
+        let body = s.strip_prefix("PVT_K1_").ok_or(key::Error::UnknownKeyFormat)?;
+        let data = base58::decode(body)?;
+        if data.len() != 36 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
+        }
+        let (key_bytes, checksum) = data.split_at(32);
+        if checksum != curve_checksum(key_bytes, CurveType::K1).as_slice() {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        Ok(SecretKey {
+            compressed: true,
+            network: Mainnet,
+            inner: SecretKeyInner::K1(secp256k1::SecretKey::parse_slice(key_bytes)?),
+        })
+    }
 
-        Signature {
-            recv_id,
-            sig,
+    /// Parse the `PVT_R1_<base58(key_bytes || checksum)>` string encoding of an R1 key.
+    pub fn from_pvt_r1_string(s: &str) -> Result<SecretKey, key::Error> {
+        let body = s.strip_prefix("PVT_R1_").ok_or(key::Error::UnknownKeyFormat)?;
+        let data = base58::decode(body)?;
+        if data.len() != 36 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
         }
+        let (key_bytes, checksum) = data.split_at(32);
+        if checksum != curve_checksum(key_bytes, CurveType::R1).as_slice() {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        Ok(SecretKey {
+            compressed: true,
+            network: Mainnet,
+            inner: SecretKeyInner::R1(p256::SecretKey::from_bytes(key_bytes.into()).map_err(|_| key::Error::UnknownKeyFormat)?),
+        })
+    }
+
+    /// Sign `message_slice` on whichever curve this key is on, producing a
+    /// `SIG_K1_...` or `SIG_R1_...` signature to match.
+    pub fn sign(&self, message_slice: &[u8]) -> Result<Signature, key::Error> {
+        let msg_hash = sha256::Hash::hash(&message_slice);
+
+        let inner = match &self.inner {
+            SecretKeyInner::K1(key) => {
+                let msg = secp256k1::Message::parse(&msg_hash.into_inner());
+                let (mut sig, recv_id) = secp256k1::sign(&msg, key);
+                sig.normalize_s();
+                SignatureInner::K1 { recv_id, sig }
+            }
+            SecretKeyInner::R1(key) => {
+                let signing_key = p256::ecdsa::SigningKey::from(key.clone());
+                let (sig, recv_id) = signing_key
+                    .sign_prehash_recoverable(&msg_hash.into_inner())
+                    .map_err(|_| key::Error::InvalidSignature)?;
+                SignatureInner::R1 { recv_id, sig }
+            }
+        };
+
+        Ok(Signature { inner })
     }
 }
 
@@ -130,12 +237,31 @@ impl fmt::Debug for SecretKey {
 }
 
 impl FromStr for SecretKey {
-    type Err = error::Error;
-    fn from_str(s: &str) -> Result<SecretKey, error::Error> {
-        SecretKey::from_wif(s)
+    type Err = key::Error;
+    fn from_str(s: &str) -> Result<SecretKey, key::Error> {
+        if s.starts_with("PVT_K1_") {
+            SecretKey::from_pvt_string(s)
+        } else if s.starts_with("PVT_R1_") {
+            SecretKey::from_pvt_r1_string(s)
+        } else {
+            SecretKey::from_wif(s)
+        }
     }
 }
 
+/// First 4 bytes of `RIPEMD160(key_bytes || curve_suffix)`, the checksum used by the
+/// modern `PVT_<curve>_...`/`PUB_<curve>_...` key encodings (as opposed to WIF's
+/// double-SHA256 checksum).
+pub(crate) fn curve_checksum(key_bytes: &[u8], curve: CurveType) -> [u8; 4] {
+    let mut engine = ripemd160::Hash::engine();
+    engine.input(key_bytes);
+    engine.input(curve.as_str().as_bytes());
+    let hash = ripemd160::Hash::from_engine(engine);
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&hash.into_inner()[..4]);
+    checksum
+}
+
 #[cfg(test)]
 mod test {
     use super::SecretKey;
@@ -165,7 +291,32 @@ mod test {
         let sk = sk.unwrap();
         let pk = PublicKey::from(&sk);
         assert_eq!(pk.to_string(), "EOS55KuLPN3u9qii2hEhJhkdQSdaVLVPTHdwdkEhszhhCWDthQtfi");
-        let sig = sk.sign("hello".as_bytes());
+        let sig = sk.sign("hello".as_bytes()).unwrap();
         assert_eq!(sig.to_string(), "SIG_K1_KumC85Ykop62rdA7enDgHHNRNbUqBqzJoyLj5zQHJxeJepZ9EPXqJWSc1KT7Fo5QyX3EavjgYWaqjHpeCg88g457dFQYwh");
     }
+
+    #[test]
+    fn sk_pvt_string_round_trips() {
+        let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F").unwrap();
+        let pvt = sk.to_pvt_string().unwrap();
+        assert!(pvt.starts_with("PVT_K1_"));
+
+        let parsed = SecretKey::from_pvt_string(&pvt).unwrap();
+        assert_eq!(parsed.to_bytes(), sk.to_bytes());
+
+        let via_from_str: SecretKey = pvt.parse().unwrap();
+        assert_eq!(via_from_str.to_bytes(), sk.to_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sk_sign_r1_produces_a_sig_r1_signature() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate_r1(&mut rng);
+        let sig = sk.sign("hello".as_bytes()).unwrap();
+        assert!(sig.to_string().starts_with("SIG_R1_"));
+
+        // R1 keys still have no legacy WIF encoding.
+        assert!(sk.to_wif().is_err());
+    }
 }
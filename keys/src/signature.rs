@@ -0,0 +1,207 @@
+//! An ECDSA signature produced by [`crate::secret::SecretKey::sign`] on either
+//! curve, displayed in the `SIG_K1_...`/`SIG_R1_...` base58 format.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use bitcoin_hashes::{ripemd160, sha256, Hash as HashTrait, HashEngine};
+use core::fmt;
+use core::str::FromStr;
+
+use crate::base58;
+use crate::curve::CurveType;
+use crate::key;
+use crate::network::Network;
+use crate::public::{PublicKey, PublicKeyInner};
+use secp256k1;
+
+/// The curve-specific signature and recovery id backing a [`Signature`].
+#[derive(Clone, PartialEq, Eq)]
+pub enum SignatureInner {
+    /// A secp256k1 (K1) ECDSA signature, plus the recovery id needed to
+    /// recover the signer's public key from the signature alone.
+    K1 {
+        /// Which of the (up to 4) candidate points the signature recovers to.
+        recv_id: secp256k1::RecoveryId,
+        /// The (r, s) signature itself.
+        sig: secp256k1::Signature,
+    },
+    /// A NIST P-256 (R1) ECDSA signature, plus its recovery id.
+    R1 {
+        /// Which of the candidate points the signature recovers to.
+        recv_id: p256::ecdsa::RecoveryId,
+        /// The (r, s) signature itself.
+        sig: p256::ecdsa::Signature,
+    },
+}
+
+/// An ECDSA signature produced on either curve. Carries a [`CurveType`] tag so
+/// recovery, verification and display can dispatch on it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Signature {
+    /// The curve-specific signature and recovery id.
+    pub inner: SignatureInner,
+}
+
+impl Signature {
+    /// Which curve this signature was produced on.
+    pub fn curve(&self) -> CurveType {
+        match self.inner {
+            SignatureInner::K1 { .. } => CurveType::K1,
+            SignatureInner::R1 { .. } => CurveType::R1,
+        }
+    }
+
+    /// Recover the public key that produced this signature over `message_slice`.
+    ///
+    /// `message_slice` is hashed with SHA-256 exactly as [`crate::secret::SecretKey::sign`]
+    /// hashes it before signing, so recovery succeeds only against the same
+    /// message bytes that were signed.
+    pub fn recover(&self, message_slice: &[u8]) -> Result<PublicKey, key::Error> {
+        let msg_hash = sha256::Hash::hash(message_slice);
+
+        let inner = match &self.inner {
+            SignatureInner::K1 { recv_id, sig } => {
+                let msg = secp256k1::Message::parse(&msg_hash.into_inner());
+                PublicKeyInner::K1(secp256k1::recover(&msg, sig, recv_id)?)
+            }
+            SignatureInner::R1 { recv_id, sig } => {
+                let key = p256::ecdsa::VerifyingKey::recover_from_prehash(&msg_hash.into_inner(), sig, *recv_id)
+                    .map_err(|_| key::Error::InvalidSignature)?;
+                PublicKeyInner::R1(key)
+            }
+        };
+
+        Ok(PublicKey {
+            compressed: true,
+            network: Network::Mainnet,
+            inner,
+        })
+    }
+
+    /// Serialize to `header || r || s`, the layout checksummed and
+    /// base58-encoded by [`Display`](fmt::Display). For K1 the header is the
+    /// recovery id plus 31 (27, plus 4 for a compressed key); for R1 it is the
+    /// raw recovery id.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(65);
+        match &self.inner {
+            SignatureInner::K1 { recv_id, sig } => {
+                data.push(recv_id.serialize() + 31);
+                data.extend_from_slice(&sig.serialize());
+            }
+            SignatureInner::R1 { recv_id, sig } => {
+                data.push(recv_id.to_byte());
+                data.extend_from_slice(&sig.to_bytes());
+            }
+        }
+        data
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sig_bytes = self.to_bytes();
+        let curve_suffix = self.curve().as_str();
+
+        let mut engine = ripemd160::Hash::engine();
+        engine.input(&sig_bytes);
+        engine.input(curve_suffix.as_bytes());
+        let checksum = ripemd160::Hash::from_engine(engine).into_inner();
+
+        let mut data = Vec::with_capacity(69);
+        data.extend_from_slice(&sig_bytes);
+        data.extend_from_slice(&checksum[..4]);
+
+        write!(f, "SIG_{}_{}", curve_suffix, base58::encode_slice(&data))
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl FromStr for Signature {
+    type Err = key::Error;
+    fn from_str(s: &str) -> Result<Signature, key::Error> {
+        let (curve, body) = if let Some(body) = s.strip_prefix("SIG_K1_") {
+            (CurveType::K1, body)
+        } else if let Some(body) = s.strip_prefix("SIG_R1_") {
+            (CurveType::R1, body)
+        } else {
+            return Err(key::Error::UnknownKeyFormat);
+        };
+
+        let data = base58::decode(body)?;
+        if data.len() != 69 {
+            return Err(key::Error::Base58(base58::Error::InvalidLength(data.len())));
+        }
+        let (sig_bytes, checksum) = data.split_at(65);
+
+        let mut engine = ripemd160::Hash::engine();
+        engine.input(sig_bytes);
+        engine.input(curve.as_str().as_bytes());
+        let expected = ripemd160::Hash::from_engine(engine).into_inner();
+        if checksum != &expected[..4] {
+            return Err(key::Error::Base58(base58::Error::InvalidChecksum));
+        }
+
+        let inner = match curve {
+            CurveType::K1 => SignatureInner::K1 {
+                recv_id: secp256k1::RecoveryId::parse(sig_bytes[0].wrapping_sub(31))?,
+                sig: secp256k1::Signature::parse_standard_slice(&sig_bytes[1..])?,
+            },
+            CurveType::R1 => SignatureInner::R1 {
+                recv_id: p256::ecdsa::RecoveryId::from_byte(sig_bytes[0]).ok_or(key::Error::InvalidSignature)?,
+                sig: p256::ecdsa::Signature::from_slice(&sig_bytes[1..]).map_err(|_| key::Error::InvalidSignature)?,
+            },
+        };
+
+        Ok(Signature { inner })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::secret::SecretKey;
+    #[cfg(feature = "std")]
+    use rand::thread_rng;
+
+    #[test]
+    fn recover_returns_the_signing_key() {
+        let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F").unwrap();
+        let pk = PublicKey::from(&sk);
+        let sig = sk.sign("hello".as_bytes()).unwrap();
+
+        let recovered = sig.recover("hello".as_bytes()).unwrap();
+        assert_eq!(recovered.to_bytes(), pk.to_bytes());
+    }
+
+    #[test]
+    fn verify_accepts_a_matching_signature_and_rejects_a_tampered_message() {
+        let sk = SecretKey::from_wif("5KJVA9P4xsiRC3zPy1KPa3GA6ffvmyZSxhKPbE924YJphvSCG4F").unwrap();
+        let pk = PublicKey::from(&sk);
+        let sig = sk.sign("hello".as_bytes()).unwrap();
+
+        assert!(pk.verify("hello".as_bytes(), &sig));
+        assert!(!pk.verify("goodbye".as_bytes(), &sig));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn r1_sign_verify_and_recover_round_trip() {
+        let mut rng = thread_rng();
+        let sk = SecretKey::generate_r1(&mut rng);
+        let pk = PublicKey::from(&sk);
+        let sig = sk.sign("hello".as_bytes()).unwrap();
+
+        assert!(sig.to_string().starts_with("SIG_R1_"));
+        assert!(pk.verify("hello".as_bytes(), &sig));
+        assert!(!pk.verify("goodbye".as_bytes(), &sig));
+
+        let recovered = sig.recover("hello".as_bytes()).unwrap();
+        assert_eq!(recovered.to_bytes(), pk.to_bytes());
+    }
+}